@@ -0,0 +1,56 @@
+// A single error type for handlers to return via `error::Result<T>`, so every
+// failure gets a consistent status code and `{"error": "..."}` JSON body.
+
+use axum::{http::StatusCode, response::IntoResponse, response::Response, Json};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("bad request: {0}")]
+    BadRequest(String),
+
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        AppError::Internal(err.into())
+    }
+}
+
+impl From<axum::Error> for AppError {
+    fn from(err: axum::Error) -> Self {
+        AppError::Internal(err.into())
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, message) = match &self {
+            AppError::NotFound(message) => (StatusCode::NOT_FOUND, message.clone()),
+            AppError::BadRequest(message) => (StatusCode::BAD_REQUEST, message.clone()),
+            AppError::Unauthorized(message) => (StatusCode::UNAUTHORIZED, message.clone()),
+            AppError::Internal(err) => {
+                // Internal errors may carry details unsafe to expose to the
+                // client (paths, query strings, ...), so log them and return
+                // a generic message instead.
+                tracing::error!("internal error: {err:#}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal server error".to_string(),
+                )
+            }
+        };
+
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
+}
+
+/// Shorthand for handler return types: `error::Result<Json<Thing>>`.
+pub type Result<T> = std::result::Result<T, AppError>;
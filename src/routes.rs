@@ -0,0 +1,104 @@
+// Route definitions, using `axum_extra`'s `TypedPath` so a path and its
+// handler can't drift apart the way `.route("/", any(root))` let them.
+//
+// `/api/protected` is gated behind `auth::require_auth` as the
+// protected-route example; `root`, `health_check`, and the 404 fallback
+// stay public.
+
+use axum::extract::State;
+use axum::http::Uri;
+use axum::{middleware, Json, Router};
+use axum_extra::routing::{RouterExt, TypedPath};
+use serde::{Deserialize, Serialize};
+use tower::ServiceBuilder;
+use tower_http::trace::TraceLayer;
+
+use crate::auth;
+use crate::error::{self, AppError};
+use crate::state::SharedState;
+
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/")]
+pub struct Root;
+
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/api/healthcheck")]
+pub struct HealthCheck;
+
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/api/protected")]
+pub struct Protected;
+
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/api/token")]
+pub struct IssueToken;
+
+#[derive(Deserialize)]
+pub struct IssueTokenRequest {
+    pub sub: String,
+}
+
+#[derive(Serialize)]
+pub struct IssueTokenResponse {
+    pub token: String,
+}
+
+/// Assembles every typed route plus the JSON 404 fallback for anything else.
+pub fn router(state: SharedState) -> Router {
+    let mut protected = Router::new().typed_get(protected);
+    if state.config.auth_enabled {
+        protected = protected
+            .route_layer(middleware::from_fn_with_state(state.clone(), auth::require_auth));
+    }
+
+    Router::new()
+        .merge(protected)
+        .typed_get(root)
+        .typed_get(health_check)
+        .typed_post(issue_token)
+        .fallback(handle_request)
+        .layer(ServiceBuilder::new().layer(TraceLayer::new_for_http()))
+        .with_state(state)
+}
+
+// This is our route handler, for the route root
+async fn root(_: Root, State(_state): State<SharedState>) -> error::Result<&'static str> {
+    Ok("Hello, World!")
+}
+
+// Example protected route: reachable only with a valid bearer token when
+// `auth_enabled` is set. Get one from `POST /api/token`.
+async fn protected(_: Protected, State(_state): State<SharedState>) -> error::Result<&'static str> {
+    Ok("authenticated")
+}
+
+// Mints a bearer token for local testing. There's no real login flow (or
+// password/credential store) in this starter yet, so this just trusts
+// whatever `sub` the caller asks for.
+async fn issue_token(
+    _: IssueToken,
+    State(state): State<SharedState>,
+    Json(payload): Json<IssueTokenRequest>,
+) -> error::Result<Json<IssueTokenResponse>> {
+    if payload.sub.trim().is_empty() {
+        return Err(AppError::BadRequest("sub must not be empty".into()));
+    }
+
+    let token = auth::issue_token(&state, payload.sub).map_err(AppError::Internal)?;
+    Ok(Json(IssueTokenResponse { token }))
+}
+
+// Pings the database pool so this endpoint actually reflects whether the
+// service can serve traffic, rather than just that the process is alive.
+async fn health_check(_: HealthCheck, State(state): State<SharedState>) -> error::Result<&'static str> {
+    sqlx::query("SELECT 1")
+        .execute(&state.db)
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+    Ok("ok")
+}
+
+// 通用请求处理函数，用于处理所有其他路由
+async fn handle_request(State(_state): State<SharedState>, uri: Uri) -> error::Result<()> {
+    Err(AppError::NotFound(format!("route not found: {}", uri.path())))
+}
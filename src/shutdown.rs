@@ -0,0 +1,41 @@
+// Resolves on Ctrl+C or, on Unix, SIGTERM (what `kubectl delete pod` sends).
+
+use std::time::Duration;
+
+/// Waits for a shutdown signal, logs it, and arms a watchdog that forces the
+/// process to exit after `drain_timeout` if graceful shutdown hasn't
+/// finished by then.
+pub async fn signal(drain_timeout: Duration) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    tracing::info!("received shutdown signal, draining connections");
+
+    tokio::spawn(async move {
+        tokio::time::sleep(drain_timeout).await;
+        tracing::warn!(
+            "drain timeout of {:?} elapsed before shutdown completed, forcing exit",
+            drain_timeout
+        );
+        std::process::exit(1);
+    });
+}
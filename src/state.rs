@@ -0,0 +1,29 @@
+// Shared application state, handed to handlers via axum's `State` extractor.
+
+use std::sync::Arc;
+
+use sqlx::postgres::{PgPool, PgPoolOptions};
+
+use crate::config::Settings;
+
+pub struct AppState {
+    pub config: Settings,
+    pub db: PgPool,
+}
+
+impl AppState {
+    /// Builds the pool lazily (`connect_lazy`), so a fresh clone with no
+    /// Postgres running can still `cargo run` and serve routes that don't
+    /// touch the database; the first real query against an unreachable
+    /// `database_url` is what surfaces the connection error.
+    pub fn new(config: Settings) -> anyhow::Result<Self> {
+        let db = PgPoolOptions::new()
+            .max_connections(5)
+            .connect_lazy(&config.database_url)?;
+
+        Ok(Self { config, db })
+    }
+}
+
+/// The type handlers actually extract via `State<SharedState>`.
+pub type SharedState = Arc<AppState>;